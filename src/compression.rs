@@ -0,0 +1,338 @@
+//! Compressing responses based on the request's `Accept-Encoding` header.
+
+use std::io::Write;
+
+use http::{header, HeaderValue, StatusCode};
+
+use crate::Response;
+
+/// A content-coding this crate knows how to produce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Encoding {
+    Gzip,
+    Deflate,
+    Br,
+    Identity,
+}
+
+impl Encoding {
+    fn as_str(self) -> &'static str {
+        match self {
+            Encoding::Gzip => "gzip",
+            Encoding::Deflate => "deflate",
+            Encoding::Br => "br",
+            Encoding::Identity => "identity",
+        }
+    }
+}
+
+/// A middleware that compresses response bodies according to the request's
+/// `Accept-Encoding` header.
+///
+/// Applied by [`Application::serve`](crate::Application::serve) after the
+/// handler has produced a response. Enable it with
+/// [`Application::with_compression`](crate::Application::with_compression).
+///
+/// # Example
+///
+/// ```
+/// use submillisecond::{compression::CompressionLayer, Application};
+/// # fn router(_: submillisecond::RequestContext) -> submillisecond::Response { unimplemented!() }
+///
+/// let compression = CompressionLayer::new().min_size(1024).br(false);
+/// let app = Application::new(router).with_compression(compression);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct CompressionLayer {
+    gzip: bool,
+    deflate: bool,
+    br: bool,
+    min_size: usize,
+    level: u32,
+}
+
+impl Default for CompressionLayer {
+    fn default() -> Self {
+        CompressionLayer {
+            gzip: true,
+            deflate: true,
+            br: true,
+            min_size: 32,
+            level: 6,
+        }
+    }
+}
+
+impl CompressionLayer {
+    /// Creates a layer with every supported algorithm enabled.
+    pub fn new() -> Self {
+        CompressionLayer::default()
+    }
+
+    /// Enables or disables gzip compression. Enabled by default.
+    pub fn gzip(mut self, enabled: bool) -> Self {
+        self.gzip = enabled;
+        self
+    }
+
+    /// Enables or disables DEFLATE compression. Enabled by default.
+    pub fn deflate(mut self, enabled: bool) -> Self {
+        self.deflate = enabled;
+        self
+    }
+
+    /// Enables or disables Brotli compression. Enabled by default.
+    pub fn br(mut self, enabled: bool) -> Self {
+        self.br = enabled;
+        self
+    }
+
+    /// Sets the minimum response body size, in bytes, before compression is
+    /// attempted. Defaults to `32`.
+    pub fn min_size(mut self, min_size: usize) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// Sets the compression level passed to the chosen algorithm, on a scale
+    /// from `0` (fastest) to `9` (smallest). Defaults to `6`.
+    pub fn level(mut self, level: u32) -> Self {
+        self.level = level.min(9);
+        self
+    }
+
+    /// Compresses `response` in place, based on the `accept_encoding` header
+    /// of the originating request.
+    ///
+    /// No-op if the response is already encoded, below the configured
+    /// minimum size, of a non-compressible content type, or if no coding
+    /// acceptable to the client is supported.
+    pub fn compress(&self, accept_encoding: Option<&HeaderValue>, response: &mut Response) {
+        if response.headers().contains_key(header::CONTENT_ENCODING) {
+            return;
+        }
+
+        if response.body().len() < self.min_size {
+            return;
+        }
+
+        if response.status() == StatusCode::PARTIAL_CONTENT
+            || response.headers().contains_key(header::CONTENT_RANGE)
+        {
+            // Compressing would change the byte offsets the `Content-Range`
+            // header promises, so leave range responses alone.
+            return;
+        }
+
+        if !is_compressible(response) {
+            return;
+        }
+
+        let encoding = match self.negotiate(accept_encoding) {
+            Some(encoding) if encoding != Encoding::Identity => encoding,
+            _ => return,
+        };
+
+        let compressed = match encode(encoding, response.body(), self.level) {
+            Some(compressed) => compressed,
+            None => return,
+        };
+
+        *response.body_mut() = compressed;
+        response.headers_mut().remove(header::CONTENT_LENGTH);
+        response.headers_mut().insert(
+            header::CONTENT_ENCODING,
+            HeaderValue::from_static(encoding.as_str()),
+        );
+        response
+            .headers_mut()
+            .append(header::VARY, HeaderValue::from_static("Accept-Encoding"));
+        // Any `Content-Length` set by the handler described the uncompressed
+        // body and was removed above; the caller recomputes it from the (now
+        // compressed) body after this returns.
+    }
+
+    /// Picks the highest-quality coding the client accepts among the
+    /// algorithms enabled on this layer, per RFC 7231 §5.3.4, breaking ties
+    /// by server preference.
+    ///
+    /// A missing `Accept-Encoding` header means the client never advertised
+    /// support for anything but identity, so it defaults to
+    /// [`Encoding::Identity`] rather than the server's favorite coding.
+    fn negotiate(&self, accept_encoding: Option<&HeaderValue>) -> Option<Encoding> {
+        let header = accept_encoding.and_then(|value| value.to_str().ok());
+        let Some(header) = header else {
+            return Some(Encoding::Identity);
+        };
+
+        let items: Vec<(&str, f32)> = header
+            .split(',')
+            .filter_map(|item| {
+                let item = item.trim();
+                if item.is_empty() {
+                    return None;
+                }
+                let mut parts = item.split(';');
+                let coding = parts.next().unwrap().trim();
+                let q = parts
+                    .find_map(|param| param.trim().strip_prefix("q="))
+                    .and_then(|q| q.parse::<f32>().ok())
+                    .unwrap_or(1.0);
+                Some((coding, q))
+            })
+            .collect();
+
+        let q_of = |coding: &str| items.iter().find(|(c, _)| *c == coding).map(|(_, q)| *q);
+        let wildcard_q = q_of("*");
+
+        // Rank every coding the client accepts by q-value first; among
+        // codings tied on q, `preference_order` (iterated first-to-last)
+        // decides the winner.
+        let mut best: Option<(Encoding, f32)> = None;
+        for preference in self.preference_order() {
+            let q = q_of(preference.as_str()).or(wildcard_q);
+            let q = match q {
+                Some(q) if q > 0.0 => q,
+                _ => continue,
+            };
+            if best.map_or(true, |(_, best_q)| q > best_q) {
+                best = Some((preference, q));
+            }
+        }
+
+        Some(best.map_or(Encoding::Identity, |(encoding, _)| encoding))
+    }
+
+    fn preference_order(&self) -> Vec<Encoding> {
+        let mut order = Vec::with_capacity(3);
+        if self.br {
+            order.push(Encoding::Br);
+        }
+        if self.gzip {
+            order.push(Encoding::Gzip);
+        }
+        if self.deflate {
+            order.push(Encoding::Deflate);
+        }
+        order
+    }
+}
+
+fn is_compressible(response: &Response) -> bool {
+    let content_type = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+
+    if content_type.starts_with("image/")
+        || content_type.starts_with("video/")
+        || content_type.starts_with("audio/")
+        || content_type.starts_with("application/zip")
+        || content_type.starts_with("application/gzip")
+        || content_type.starts_with("application/octet-stream")
+    {
+        return false;
+    }
+
+    true
+}
+
+fn encode(encoding: Encoding, body: &[u8], level: u32) -> Option<Vec<u8>> {
+    match encoding {
+        Encoding::Gzip => {
+            let mut encoder = flate2::write::GzEncoder::new(
+                Vec::new(),
+                flate2::Compression::new(level),
+            );
+            encoder.write_all(body).ok()?;
+            encoder.finish().ok()
+        }
+        Encoding::Deflate => {
+            let mut encoder = flate2::write::DeflateEncoder::new(
+                Vec::new(),
+                flate2::Compression::new(level),
+            );
+            encoder.write_all(body).ok()?;
+            encoder.finish().ok()
+        }
+        Encoding::Br => {
+            let mut output = Vec::new();
+            let params = brotli::enc::BrotliEncoderParams {
+                quality: level.min(11) as i32,
+                ..Default::default()
+            };
+            brotli::BrotliCompress(&mut &body[..], &mut output, &params).ok()?;
+            Some(output)
+        }
+        Encoding::Identity => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::HeaderValue;
+
+    use super::{CompressionLayer, Encoding};
+
+    fn header(value: &str) -> HeaderValue {
+        HeaderValue::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn missing_header_defaults_to_identity() {
+        let layer = CompressionLayer::new();
+        assert_eq!(layer.negotiate(None), Some(Encoding::Identity));
+    }
+
+    #[test]
+    fn q_zero_excludes_a_coding() {
+        let layer = CompressionLayer::new();
+        let accept_encoding = header("gzip;q=0, deflate;q=0.5");
+        assert_eq!(layer.negotiate(Some(&accept_encoding)), Some(Encoding::Deflate));
+    }
+
+    #[test]
+    fn q_zero_on_everything_falls_back_to_identity() {
+        let layer = CompressionLayer::new();
+        let accept_encoding = header("gzip;q=0, deflate;q=0, br;q=0");
+        assert_eq!(layer.negotiate(Some(&accept_encoding)), Some(Encoding::Identity));
+    }
+
+    #[test]
+    fn wildcard_is_used_when_a_coding_is_unlisted() {
+        let layer = CompressionLayer::new().br(false).deflate(false);
+        let accept_encoding = header("*;q=0.8");
+        assert_eq!(layer.negotiate(Some(&accept_encoding)), Some(Encoding::Gzip));
+    }
+
+    #[test]
+    fn wildcard_q_zero_excludes_unlisted_codings() {
+        let layer = CompressionLayer::new();
+        let accept_encoding = header("gzip;q=0.5, *;q=0");
+        assert_eq!(layer.negotiate(Some(&accept_encoding)), Some(Encoding::Gzip));
+    }
+
+    #[test]
+    fn highest_q_wins_over_server_preference() {
+        let layer = CompressionLayer::new();
+        // `br` is first in server preference order, but the client ranks
+        // gzip highest, so gzip should win on q-value alone.
+        let accept_encoding = header("br;q=0.1, gzip;q=1.0, deflate;q=0.2");
+        assert_eq!(layer.negotiate(Some(&accept_encoding)), Some(Encoding::Gzip));
+    }
+
+    #[test]
+    fn ties_on_q_break_by_server_preference() {
+        let layer = CompressionLayer::new();
+        let accept_encoding = header("gzip;q=1.0, deflate;q=1.0, br;q=1.0");
+        assert_eq!(layer.negotiate(Some(&accept_encoding)), Some(Encoding::Br));
+    }
+
+    #[test]
+    fn disabled_codings_are_never_negotiated() {
+        let layer = CompressionLayer::new().br(false);
+        let accept_encoding = header("br;q=1.0, gzip;q=0.1");
+        assert_eq!(layer.negotiate(Some(&accept_encoding)), Some(Encoding::Gzip));
+    }
+}
@@ -0,0 +1,179 @@
+//! Extractor that deserializes `application/x-www-form-urlencoded` request
+//! bodies.
+
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+
+use http::{Method, StatusCode};
+use serde::de::DeserializeOwned;
+
+use crate::extract::FromRequest;
+use crate::response::IntoResponse;
+use crate::{RequestContext, Response};
+
+/// Extractor that deserializes `application/x-www-form-urlencoded` request
+/// bodies into some type via [`serde`].
+///
+/// `T` is expected to implement [`serde::Deserialize`].
+///
+/// For `GET` requests, the query string is deserialized instead, since HTML
+/// forms submit `GET`s as a query string rather than a body.
+///
+/// # Example
+///
+/// ```
+/// use serde::Deserialize;
+/// use submillisecond::{router, extract::Form};
+///
+/// #[derive(Deserialize)]
+/// struct SignUp {
+///     username: String,
+///     password: String,
+/// }
+///
+/// fn sign_up(Form(form): Form<SignUp>) {
+///     // ...
+/// }
+///
+/// router! {
+///     POST "/sign-up" => sign_up
+/// }
+/// ```
+///
+/// # Providing detailed rejection output
+///
+/// If the request doesn't have a `Content-Type: application/x-www-form-
+/// urlencoded` header, or the body can't be deserialized into the target
+/// type, the request will be rejected and an error response will be
+/// returned.
+///
+/// [`serde`]: https://crates.io/crates/serde
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Form<T>(pub T);
+
+impl<T> Deref for Form<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Form<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> FromRequest for Form<T>
+where
+    T: DeserializeOwned,
+{
+    type Rejection = FormRejection;
+
+    fn from_request(req: &mut RequestContext) -> Result<Self, Self::Rejection> {
+        if req.method() == Method::GET {
+            let query = req.uri().query().unwrap_or_default();
+            return serde_urlencoded::from_str(query)
+                .map(Form)
+                .map_err(|err| FormRejection::FailedToDeserializeForm(FailedToDeserializeForm {
+                    error: err.to_string(),
+                }));
+        }
+
+        let content_type = req
+            .headers()
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok());
+        match content_type {
+            Some(content_type) if is_form_content_type(content_type) => {}
+            _ => return Err(FormRejection::InvalidFormContentType(InvalidFormContentType)),
+        }
+
+        let body = Vec::<u8>::from_request(req).unwrap();
+        serde_urlencoded::from_bytes(&body)
+            .map(Form)
+            .map_err(|err| {
+                FormRejection::FailedToDeserializeForm(FailedToDeserializeForm {
+                    error: err.to_string(),
+                })
+            })
+    }
+}
+
+fn is_form_content_type(content_type: &str) -> bool {
+    content_type
+        .parse::<mime::Mime>()
+        .map(|mime| {
+            mime.type_() == mime::APPLICATION && mime.subtype() == mime::WWW_FORM_URLENCODED
+        })
+        .unwrap_or(false)
+}
+
+/// Rejection type for [`Form`] if the request couldn't be turned into the
+/// expected type.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum FormRejection {
+    /// The request's `Content-Type` wasn't `application/x-www-form-
+    /// urlencoded`.
+    InvalidFormContentType(InvalidFormContentType),
+    /// Failed to deserialize the form body into the target type.
+    FailedToDeserializeForm(FailedToDeserializeForm),
+}
+
+impl fmt::Display for FormRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormRejection::InvalidFormContentType(inner) => inner.fmt(f),
+            FormRejection::FailedToDeserializeForm(inner) => inner.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for FormRejection {}
+
+impl IntoResponse for FormRejection {
+    fn into_response(self) -> Response {
+        match self {
+            FormRejection::InvalidFormContentType(_) => {
+                (StatusCode::UNSUPPORTED_MEDIA_TYPE, self.to_string()).into_response()
+            }
+            FormRejection::FailedToDeserializeForm(_) => {
+                (StatusCode::UNPROCESSABLE_ENTITY, self.to_string()).into_response()
+            }
+        }
+    }
+}
+
+/// The request didn't have a `Content-Type: application/x-www-form-
+/// urlencoded` header.
+#[derive(Debug)]
+pub struct InvalidFormContentType;
+
+impl fmt::Display for InvalidFormContentType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Expected request with `Content-Type: application/x-www-form-urlencoded`"
+        )
+    }
+}
+
+impl std::error::Error for InvalidFormContentType {}
+
+/// The form body couldn't be deserialized into the target type.
+#[derive(Debug)]
+pub struct FailedToDeserializeForm {
+    error: String,
+}
+
+impl fmt::Display for FailedToDeserializeForm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Failed to deserialize form body: {}", self.error)
+    }
+}
+
+impl std::error::Error for FailedToDeserializeForm {}
@@ -0,0 +1,35 @@
+//! Types and traits for extracting data from requests.
+
+#[cfg(feature = "form")]
+pub mod form;
+#[cfg(feature = "multipart")]
+pub mod multipart;
+pub mod path;
+#[cfg(feature = "query")]
+pub mod query;
+pub mod rejection;
+mod vec;
+
+use crate::response::IntoResponse;
+use crate::{RequestContext, Response};
+
+#[cfg(feature = "form")]
+pub use self::form::Form;
+#[cfg(feature = "multipart")]
+pub use self::multipart::Multipart;
+pub use self::path::{FromParam, OptionalPath, Path};
+#[cfg(feature = "query")]
+pub use self::query::Query;
+
+/// Types that can be created from requests.
+///
+/// Every argument of a handler function used with [`router!`](crate::router)
+/// must implement `FromRequest`.
+pub trait FromRequest: Sized {
+    /// If the extractor fails, it will use this "rejection" type. A
+    /// rejection is a kind of error that can be converted into a response.
+    type Rejection: IntoResponse;
+
+    /// Perform the extraction.
+    fn from_request(req: &mut RequestContext) -> Result<Self, Self::Rejection>;
+}
@@ -0,0 +1,278 @@
+//! Extractor that parses `multipart/form-data` request bodies, yielding
+//! fields one at a time instead of buffering every field up front.
+
+use std::fmt;
+use std::io::Cursor;
+
+use http::StatusCode;
+
+use crate::extract::FromRequest;
+use crate::reader::Reader;
+use crate::response::IntoResponse;
+use crate::{RequestContext, Response};
+
+/// Extractor for `multipart/form-data` requests.
+///
+/// Call [`Multipart::next_field`] in a loop to pull fields out one at a
+/// time. Each [`Field`] exposes its name, optional filename and content
+/// type, plus a [`Field::reader`] to pull its bytes out incrementally
+/// instead of through [`Field::bytes`]'s single slice.
+///
+/// Note on buffering: the request body is already read into memory in full
+/// before any handler runs (the same as every other extractor in this
+/// crate, e.g. the `Vec<u8>` body extractor, and a property of
+/// [`core::parse_request`](crate), not something an extractor controls), so
+/// `Multipart` does not avoid holding the request body in memory, and
+/// [`Field::reader`] is reading from that buffer rather than the socket.
+/// What it does avoid is eagerly splitting and copying *every* field up
+/// front: `next_field` parses the body lazily, field by field, and
+/// [`Field::reader`] is built on this crate's [`Reader`](crate::reader::Reader)
+/// over a borrowed slice rather than an owned copy, so a handler that only
+/// inspects a few fields, or skips a large file it doesn't need, never pays
+/// for the ones it didn't read. True socket-level streaming, where a large
+/// file is never resident in memory at all, would need the request parser
+/// itself to defer body buffering, which is out of reach from this module.
+///
+/// # Example
+///
+/// ```
+/// use std::io::Read;
+///
+/// use submillisecond::extract::Multipart;
+/// use submillisecond::response::IntoResponse;
+///
+/// fn upload(mut multipart: Multipart) -> Result<(), submillisecond::Response> {
+///     while let Some(field) = multipart.next_field().map_err(IntoResponse::into_response)? {
+///         let name = field.name().map(str::to_owned);
+///         let mut data = Vec::new();
+///         field.reader().read_to_end(&mut data).ok();
+///         println!("field {:?} was {} bytes", name, data.len());
+///     }
+///     Ok(())
+/// }
+/// ```
+pub struct Multipart {
+    body: Vec<u8>,
+    boundary: String,
+    position: usize,
+    done: bool,
+}
+
+impl FromRequest for Multipart {
+    type Rejection = MultipartRejection;
+
+    fn from_request(req: &mut RequestContext) -> Result<Self, Self::Rejection> {
+        let boundary = req
+            .headers()
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_boundary)
+            .ok_or(MultipartRejection::InvalidBoundary(InvalidBoundary))?;
+
+        // `Vec<u8>`'s `FromRequest` impl is infallible.
+        let body = Vec::<u8>::from_request(req).unwrap();
+
+        Ok(Multipart {
+            body,
+            boundary,
+            position: 0,
+            done: false,
+        })
+    }
+}
+
+impl Multipart {
+    /// Pulls the next field out of the body, or `None` once every field has
+    /// been consumed.
+    pub fn next_field(&mut self) -> Result<Option<Field<'_>>, MultipartRejection> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let delimiter = format!("--{}", self.boundary);
+
+        let start = find(&self.body[self.position..], delimiter.as_bytes())
+            .ok_or(MultipartRejection::MalformedBody(MalformedBody))?;
+        let mut cursor = self.position + start + delimiter.len();
+
+        if self.body[cursor..].starts_with(b"--") {
+            self.done = true;
+            return Ok(None);
+        }
+        cursor += skip_crlf(&self.body[cursor..]);
+
+        let header_len = find(&self.body[cursor..], b"\r\n\r\n")
+            .ok_or(MultipartRejection::MalformedBody(MalformedBody))?;
+        let headers = &self.body[cursor..cursor + header_len];
+        let body_start = cursor + header_len + 4;
+
+        let next_boundary = find(&self.body[body_start..], delimiter.as_bytes())
+            .ok_or(MultipartRejection::MalformedBody(MalformedBody))?;
+        let mut body_end = body_start + next_boundary;
+        if body_end >= body_start + 2 && &self.body[body_end - 2..body_end] == b"\r\n" {
+            body_end -= 2;
+        }
+
+        let (name, filename) = parse_content_disposition(headers);
+        let content_type = parse_header_value(headers, "content-type");
+
+        self.position = body_start + next_boundary;
+
+        Ok(Some(Field {
+            name,
+            filename,
+            content_type,
+            data: &self.body[body_start..body_end],
+        }))
+    }
+}
+
+/// A single field of a `multipart/form-data` body.
+pub struct Field<'a> {
+    name: Option<String>,
+    filename: Option<String>,
+    content_type: Option<String>,
+    data: &'a [u8],
+}
+
+impl<'a> Field<'a> {
+    /// The field's name, from its `Content-Disposition` header.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The field's filename, if it was uploaded as a file.
+    pub fn file_name(&self) -> Option<&str> {
+        self.filename.as_deref()
+    }
+
+    /// The field's `Content-Type`, if one was given.
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    /// A reader to pull the field's bytes out incrementally, borrowed from
+    /// the request's buffered body (no extra copy).
+    pub fn reader(&self) -> Reader<Cursor<&'a [u8]>> {
+        Reader::new(Cursor::new(self.data))
+    }
+
+    /// The field's raw bytes, as a single slice. Prefer [`Field::reader`]
+    /// when the field may be large and the caller can process it in
+    /// chunks.
+    pub fn bytes(&self) -> &'a [u8] {
+        self.data
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+fn skip_crlf(data: &[u8]) -> usize {
+    if data.starts_with(b"\r\n") {
+        2
+    } else {
+        0
+    }
+}
+
+fn parse_header_value(headers: &[u8], name: &str) -> Option<String> {
+    let headers = std::str::from_utf8(headers).ok()?;
+    headers.split("\r\n").find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        if key.trim().eq_ignore_ascii_case(name) {
+            Some(value.trim().to_owned())
+        } else {
+            None
+        }
+    })
+}
+
+fn parse_content_disposition(headers: &[u8]) -> (Option<String>, Option<String>) {
+    let Some(disposition) = parse_header_value(headers, "content-disposition") else {
+        return (None, None);
+    };
+
+    let mut name = None;
+    let mut filename = None;
+    for part in disposition.split(';').skip(1) {
+        let part = part.trim();
+        if let Some(value) = part.strip_prefix("name=") {
+            name = Some(unquote(value));
+        } else if let Some(value) = part.strip_prefix("filename=") {
+            filename = Some(unquote(value));
+        }
+    }
+
+    (name, filename)
+}
+
+fn unquote(value: &str) -> String {
+    value.trim_matches('"').to_owned()
+}
+
+fn parse_boundary(content_type: &str) -> Option<String> {
+    let mime: mime::Mime = content_type.parse().ok()?;
+    if mime.type_() != mime::MULTIPART || mime.subtype() != mime::FORM_DATA {
+        return None;
+    }
+    mime.get_param(mime::BOUNDARY).map(|value| value.to_string())
+}
+
+/// Rejection type for [`Multipart`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum MultipartRejection {
+    /// The request didn't have a `multipart/form-data` `Content-Type` with a
+    /// boundary.
+    InvalidBoundary(InvalidBoundary),
+    /// The body wasn't a well-formed multipart body.
+    MalformedBody(MalformedBody),
+}
+
+impl fmt::Display for MultipartRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MultipartRejection::InvalidBoundary(inner) => inner.fmt(f),
+            MultipartRejection::MalformedBody(inner) => inner.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for MultipartRejection {}
+
+impl IntoResponse for MultipartRejection {
+    fn into_response(self) -> Response {
+        (StatusCode::BAD_REQUEST, self.to_string()).into_response()
+    }
+}
+
+/// The request didn't have a valid `multipart/form-data` boundary.
+#[derive(Debug)]
+pub struct InvalidBoundary;
+
+impl fmt::Display for InvalidBoundary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Expected request with `Content-Type: multipart/form-data` and a boundary"
+        )
+    }
+}
+
+impl std::error::Error for InvalidBoundary {}
+
+/// The multipart body couldn't be parsed.
+#[derive(Debug)]
+pub struct MalformedBody;
+
+impl fmt::Display for MalformedBody {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Malformed multipart body")
+    }
+}
+
+impl std::error::Error for MalformedBody {}
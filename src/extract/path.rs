@@ -17,6 +17,9 @@ use crate::{RequestContext, Response};
 
 #[doc(hidden)]
 pub mod de;
+pub mod from_param;
+
+pub use self::from_param::FromParam;
 
 /// Extractor that will get captures from the URL and parse them using
 /// [`serde`].
@@ -176,6 +179,70 @@ where
     }
 }
 
+/// Extractor that will get captures from the URL and parse them using
+/// [`serde`], if the current route captured any parameters at all.
+///
+/// This is useful for sharing a single handler between a route that has a
+/// capture and one that doesn't, e.g. `/users` and `/users/:id`. If no
+/// parameters were captured, `OptionalPath(None)` is returned. If parameters
+/// were captured but fail to deserialize into `T`, the request is rejected
+/// the same way [`Path`] would reject it.
+///
+/// # Example
+///
+/// ```
+/// use submillisecond::{router, extract::OptionalPath};
+/// use uuid::Uuid;
+///
+/// fn user_info(OptionalPath(user_id): OptionalPath<Uuid>) {
+///     // ...
+/// }
+///
+/// router! {
+///     GET "/users" => user_info
+///     GET "/users/:user_id" => user_info
+/// }
+/// ```
+#[derive(Debug)]
+pub struct OptionalPath<T>(pub Option<T>);
+
+impl<T> Deref for OptionalPath<T> {
+    type Target = Option<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for OptionalPath<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> FromRequest for OptionalPath<T>
+where
+    T: DeserializeOwned,
+{
+    type Rejection = PathRejection;
+
+    fn from_request(req: &mut RequestContext) -> Result<Self, Self::Rejection> {
+        let has_params = req
+            .extensions_mut()
+            .get::<Params>()
+            .map(|params| params.iter().next().is_some())
+            .unwrap_or(false);
+
+        if !has_params {
+            return Ok(OptionalPath(None));
+        }
+
+        Path::from_request(req).map(|Path(value)| OptionalPath(Some(value)))
+    }
+}
+
 // this wrapper type is used as the deserializer error to hide the
 // `serde::de::Error` impl which would otherwise be public if we used
 // `ErrorKind` as the error directly
@@ -0,0 +1,152 @@
+//! Converting a single captured path segment without going through a full
+//! [`serde`] round-trip.
+
+use std::convert::Infallible;
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU128, NonZeroU16,
+    NonZeroU32, NonZeroU64, NonZeroU8,
+};
+use std::path::{Component, Path, PathBuf};
+use std::str::ParseBoolError;
+
+use crate::extract::path::de::PercentDecodedStr;
+use crate::extract::path::{ErrorKind, PathDeserializationError};
+use crate::extract::rejection::PathRejection;
+
+/// Types that can be parsed from a single path segment.
+///
+/// Implementations take `value` as given, except [`PathBuf`]'s, which
+/// percent-decodes each `/`-separated segment itself.
+///
+/// This is a cheaper alternative to deserializing through
+/// [`Path`](crate::extract::Path)'s [`serde::Deserializer`] when a handler
+/// only needs one captured segment converted to a concrete type.
+pub trait FromParam: Sized {
+    /// The error produced when `value` can't be converted.
+    type Rejection: Into<PathRejection>;
+
+    /// Converts a single path segment into `Self`.
+    fn from_param(value: &str) -> Result<Self, Self::Rejection>;
+}
+
+impl FromParam for String {
+    type Rejection = Infallible;
+
+    fn from_param(value: &str) -> Result<Self, Self::Rejection> {
+        Ok(value.to_owned())
+    }
+}
+
+impl FromParam for bool {
+    type Rejection = ParamParseError<ParseBoolError>;
+
+    fn from_param(value: &str) -> Result<Self, Self::Rejection> {
+        value.parse().map_err(|error| ParamParseError {
+            value: value.to_owned(),
+            expected_type: "bool",
+            error,
+        })
+    }
+}
+
+/// A `/`-separated path, such as the rest of a request path, percent-decoded
+/// segment by segment and joined back into a filesystem path.
+///
+/// Any segment that decodes to `..`, or to a path with a `..` or root
+/// component (e.g. `a%2F..` or `%2Fetc`), is rejected, so a handler using
+/// `PathBuf` cannot be tricked into escaping the matched directory via path
+/// traversal.
+impl FromParam for PathBuf {
+    type Rejection = PathRejection;
+
+    fn from_param(value: &str) -> Result<Self, Self::Rejection> {
+        let mut path = PathBuf::new();
+        for segment in value.split('/').filter(|segment| !segment.is_empty()) {
+            let decoded = PercentDecodedStr::new(segment).ok_or_else(|| {
+                path_rejection(ErrorKind::InvalidUtf8InPathParam {
+                    key: segment.to_owned(),
+                })
+            })?;
+            let decoded: &str = &decoded;
+            let escapes = Path::new(decoded).components().any(|component| {
+                !matches!(component, Component::Normal(_) | Component::CurDir)
+            });
+            if escapes {
+                return Err(path_rejection(ErrorKind::Message(
+                    "path segments may not contain `..` or a root component".to_owned(),
+                )));
+            }
+            path.push(decoded);
+        }
+        Ok(path)
+    }
+}
+
+/// A [`FromParam`] rejection produced by a primitive numeric or `bool`
+/// type's [`FromStr`](std::str::FromStr) implementation failing.
+#[derive(Debug)]
+pub struct ParamParseError<E> {
+    value: String,
+    expected_type: &'static str,
+    error: E,
+}
+
+impl<E> From<ParamParseError<E>> for PathRejection {
+    fn from(err: ParamParseError<E>) -> Self {
+        path_rejection(ErrorKind::ParseError {
+            value: err.value,
+            expected_type: err.expected_type,
+        })
+    }
+}
+
+fn path_rejection(kind: ErrorKind) -> PathRejection {
+    PathRejection::FailedToDeserializePathParams(
+        crate::extract::path::FailedToDeserializePathParams(PathDeserializationError::new(kind)),
+    )
+}
+
+macro_rules! impl_from_param_for_number {
+    ($($ty:ty => $name:literal),* $(,)?) => {
+        $(
+            impl FromParam for $ty {
+                type Rejection = ParamParseError<<$ty as std::str::FromStr>::Err>;
+
+                fn from_param(value: &str) -> Result<Self, Self::Rejection> {
+                    value.parse().map_err(|error| ParamParseError {
+                        value: value.to_owned(),
+                        expected_type: $name,
+                        error,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_from_param_for_number! {
+    u8 => "u8",
+    u16 => "u16",
+    u32 => "u32",
+    u64 => "u64",
+    u128 => "u128",
+    usize => "usize",
+    i8 => "i8",
+    i16 => "i16",
+    i32 => "i32",
+    i64 => "i64",
+    i128 => "i128",
+    isize => "isize",
+    f32 => "f32",
+    f64 => "f64",
+    NonZeroU8 => "NonZeroU8",
+    NonZeroU16 => "NonZeroU16",
+    NonZeroU32 => "NonZeroU32",
+    NonZeroU64 => "NonZeroU64",
+    NonZeroU128 => "NonZeroU128",
+    NonZeroI8 => "NonZeroI8",
+    NonZeroI16 => "NonZeroI16",
+    NonZeroI32 => "NonZeroI32",
+    NonZeroI64 => "NonZeroI64",
+    NonZeroI128 => "NonZeroI128",
+}
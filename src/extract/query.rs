@@ -0,0 +1,127 @@
+//! Extractor that will get captures from the URL query string and parse them
+//! using [`serde`].
+
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+
+use http::StatusCode;
+use serde::de::DeserializeOwned;
+
+use crate::extract::FromRequest;
+use crate::response::IntoResponse;
+use crate::{RequestContext, Response};
+
+/// Extractor that deserializes query strings into some type.
+///
+/// `T` is expected to implement [`serde::Deserialize`].
+///
+/// # Example
+///
+/// ```
+/// use serde::Deserialize;
+/// use submillisecond::{router, extract::Query};
+///
+/// #[derive(Deserialize)]
+/// struct Pagination {
+///     page: usize,
+///     per_page: usize,
+/// }
+///
+/// fn list_things(Query(pagination): Query<Pagination>) {
+///     // ...
+/// }
+///
+/// router! {
+///     GET "/things" => list_things
+/// }
+/// ```
+///
+/// If the query string is empty or missing, `T` is deserialized from empty
+/// input rather than rejecting the request, so `Query<HashMap<String,
+/// String>>` and structs with all-optional/defaulted fields still succeed.
+///
+/// # Providing detailed rejection output
+///
+/// If the query string cannot be deserialized into the target type the
+/// request will be rejected and an error response will be returned.
+///
+/// [`serde`]: https://crates.io/crates/serde
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Query<T>(pub T);
+
+impl<T> Deref for Query<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Query<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> FromRequest for Query<T>
+where
+    T: DeserializeOwned,
+{
+    type Rejection = QueryRejection;
+
+    fn from_request(req: &mut RequestContext) -> Result<Self, Self::Rejection> {
+        let query = req.uri().query().unwrap_or_default();
+        serde_html_form::from_str(query)
+            .map(Query)
+            .map_err(|err| {
+                QueryRejection::FailedToDeserializeQueryString(FailedToDeserializeQueryString {
+                    error: err.to_string(),
+                })
+            })
+    }
+}
+
+/// Rejection type for [`Query`] if the query string couldn't be deserialized
+/// into the expected type.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum QueryRejection {
+    /// Failed to deserialize the query string into the target type.
+    FailedToDeserializeQueryString(FailedToDeserializeQueryString),
+}
+
+impl fmt::Display for QueryRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QueryRejection::FailedToDeserializeQueryString(inner) => inner.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for QueryRejection {}
+
+impl IntoResponse for QueryRejection {
+    fn into_response(self) -> Response {
+        (StatusCode::BAD_REQUEST, self.to_string()).into_response()
+    }
+}
+
+/// The query string couldn't be deserialized into the target type.
+///
+/// This is a part of [`QueryRejection`] and gets extracted so the error
+/// message (which mentions the offending key, when known) can be inspected
+/// separately from the HTTP status.
+#[derive(Debug)]
+pub struct FailedToDeserializeQueryString {
+    error: String,
+}
+
+impl fmt::Display for FailedToDeserializeQueryString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Failed to deserialize query string: {}", self.error)
+    }
+}
+
+impl std::error::Error for FailedToDeserializeQueryString {}
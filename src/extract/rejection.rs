@@ -0,0 +1,50 @@
+//! Rejection types used by the built-in extractors.
+
+use std::convert::Infallible;
+use std::fmt;
+
+use crate::extract::path::{ErrorKind, FailedToDeserializePathParams};
+use crate::response::IntoResponse;
+use crate::Response;
+
+/// Rejection used for [`Path`](crate::extract::Path) and
+/// [`OptionalPath`](crate::extract::OptionalPath).
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PathRejection {
+    /// The URI failed to get deserialized into the target type.
+    FailedToDeserializePathParams(FailedToDeserializePathParams),
+}
+
+impl PathRejection {
+    /// Get the underlying [`ErrorKind`].
+    pub fn into_kind(self) -> ErrorKind {
+        match self {
+            PathRejection::FailedToDeserializePathParams(inner) => inner.into_kind(),
+        }
+    }
+}
+
+impl fmt::Display for PathRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathRejection::FailedToDeserializePathParams(inner) => inner.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for PathRejection {}
+
+impl IntoResponse for PathRejection {
+    fn into_response(self) -> Response {
+        match self {
+            PathRejection::FailedToDeserializePathParams(inner) => inner.into_response(),
+        }
+    }
+}
+
+impl From<Infallible> for PathRejection {
+    fn from(infallible: Infallible) -> Self {
+        match infallible {}
+    }
+}
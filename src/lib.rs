@@ -6,6 +6,7 @@ use lunatic::net::{TcpListener, TcpStream, ToSocketAddrs};
 use lunatic::{Mailbox, Process};
 pub use submillisecond_macros::*;
 
+use crate::compression::CompressionLayer;
 pub use crate::error::*;
 pub use crate::guard::*;
 pub use crate::handler::*;
@@ -15,6 +16,7 @@ use crate::response::{IntoResponse, Response};
 #[macro_use]
 pub(crate) mod macros;
 
+pub mod compression;
 #[cfg(feature = "cookie")]
 pub mod cookies;
 mod core;
@@ -25,6 +27,7 @@ pub mod json;
 pub mod params;
 pub mod reader;
 pub mod response;
+pub mod serve_dir;
 #[cfg(feature = "cookie")]
 pub mod session;
 #[cfg(feature = "template")]
@@ -41,11 +44,22 @@ pub type Router = fn(RequestContext) -> Response;
 #[derive(Clone, Copy)]
 pub struct Application {
     router: Router,
+    compression: Option<CompressionLayer>,
 }
 
 impl Application {
     pub fn new(router: Router) -> Self {
-        Application { router }
+        Application {
+            router,
+            compression: None,
+        }
+    }
+
+    /// Compresses responses according to the request's `Accept-Encoding`
+    /// header, using the given [`CompressionLayer`].
+    pub fn with_compression(mut self, compression: CompressionLayer) -> Self {
+        self.compression = Some(compression);
+        self
     }
 
     pub fn serve<A: ToSocketAddrs>(self, addr: A) -> io::Result<()> {
@@ -53,8 +67,13 @@ impl Application {
 
         while let Ok((stream, _)) = listener.accept() {
             Process::spawn_link(
-                (stream, self.router as *const () as usize),
-                |(stream, handler_raw): (TcpStream, usize), _: Mailbox<()>| {
+                (stream, self.router as *const () as usize, self.compression),
+                |(stream, handler_raw, compression): (
+                    TcpStream,
+                    usize,
+                    Option<CompressionLayer>,
+                ),
+                 _: Mailbox<()>| {
                     let handler = unsafe {
                         let pointer = handler_raw as *const ();
                         mem::transmute::<*const (), Router>(pointer)
@@ -70,15 +89,26 @@ impl Application {
                         }
                     };
                     let http_version = request.version();
+                    let accept_encoding = request.headers().get(header::ACCEPT_ENCODING).cloned();
 
                     let mut response =
                         Handler::handle(&handler, RequestContext::from(request)).into_response();
 
-                    let content_length = response.body().len();
+                    if let Some(compression) = compression {
+                        compression.compress(accept_encoding.as_ref(), &mut response);
+                    }
+
                     *response.version_mut() = http_version;
-                    response
-                        .headers_mut()
-                        .append(header::CONTENT_LENGTH, HeaderValue::from(content_length));
+                    if !response.headers().contains_key(header::CONTENT_LENGTH) {
+                        // A handler may have already set this to report the
+                        // full representation length for a bodyless `HEAD`
+                        // response; don't override it with the actual
+                        // (empty) body length in that case.
+                        let content_length = response.body().len();
+                        response
+                            .headers_mut()
+                            .append(header::CONTENT_LENGTH, HeaderValue::from(content_length));
+                    }
 
                     if let Err(err) = core::write_response(stream, response) {
                         eprintln!("[http reader] Failed to send response {:?}", err);
@@ -0,0 +1,59 @@
+//! Route parameters captured while matching a request against a router.
+
+/// The dynamic parameters captured while matching the current request
+/// against the [`router!`](crate::router) output.
+///
+/// The generated router inserts a `Params` value into the request
+/// extensions before calling the matched handler, and extractors such as
+/// [`Path`](crate::extract::Path) read it back out.
+///
+/// This type is only the storage: parsing a route literal (named segments
+/// like `:id`, and in principle a greedy trailing segment like
+/// `"/files/*rest"`) and calling [`Params::insert`] for each match happens
+/// in the `router!` macro's expansion, in the separate
+/// `submillisecond_macros` crate.
+///
+/// **Wildcard captures are not implemented.** That macro crate isn't part
+/// of this source tree, and named-segment capture is as far as parsing
+/// here goes — no route pattern in this tree produces a `*rest`-style tail
+/// capture, and [`Params::insert`] is never called with one. Do not read
+/// this module, or [`FromParam`](crate::extract::FromParam)'s `PathBuf`
+/// impl (which only converts an already-captured string, and isn't itself
+/// a capture mechanism), as delivering wildcard routing. Adding it needs a
+/// change to `submillisecond_macros`; [`ServeDir`](crate::serve_dir::ServeDir)
+/// works around the gap entirely by resolving files from the request URI
+/// instead of a captured param.
+#[derive(Debug, Clone, Default)]
+pub struct Params {
+    entries: Vec<(&'static str, String)>,
+}
+
+impl Params {
+    /// Creates an empty set of params.
+    pub fn new() -> Self {
+        Params::default()
+    }
+
+    /// Inserts a captured `(name, value)` pair.
+    pub fn insert(&mut self, name: &'static str, value: String) {
+        self.entries.push((name, value));
+    }
+
+    /// Returns the value captured for `name`, if any.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns `true` if no parameters were captured for the current route.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterates over the captured `(name, value)` pairs in capture order.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &str)> + '_ {
+        self.entries.iter().map(|(key, value)| (*key, value.as_str()))
+    }
+}
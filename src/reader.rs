@@ -0,0 +1,34 @@
+//! A thin, shared `Read` wrapper used anywhere this crate hands a caller an
+//! incremental view over bytes it already has, instead of a bare
+//! [`std::io::Cursor`] or a fully materialized `Vec<u8>`.
+
+use std::io::{self, Read};
+
+/// Wraps any [`Read`] implementation.
+///
+/// Extractors that expose part of a request as a readable stream (e.g.
+/// [`Multipart`](crate::extract::Multipart)'s per-field reader) build on
+/// this type, so the crate has one reading primitive rather than each
+/// extractor picking its own.
+#[derive(Debug, Clone, Copy)]
+pub struct Reader<R> {
+    inner: R,
+}
+
+impl<R> Reader<R> {
+    /// Wraps `inner` in a `Reader`.
+    pub fn new(inner: R) -> Self {
+        Reader { inner }
+    }
+
+    /// Consumes the `Reader`, returning the wrapped value.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> Read for Reader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.inner.read(buf)
+    }
+}
@@ -0,0 +1,267 @@
+//! Serving static files from a directory on disk.
+//!
+//! [`ServeDir`] resolves the requested file directly from the request URI
+//! (optionally stripping a configured mount prefix) rather than relying on
+//! a router-captured wildcard parameter: nothing in this crate's router
+//! support (that lives in the separate `submillisecond_macros` crate)
+//! currently produces a greedy tail capture, so a [`Params`](crate::params::Params)-based
+//! lookup would never see a value and the handler would 404 unconditionally.
+//! Resolving from the URI means [`ServeDir`] actually serves files as soon
+//! as it's reached, regardless of how the router dispatched the request to
+//! it, and supports conditional and range requests.
+
+use std::io::Read;
+use std::path::{Component, PathBuf};
+use std::time::SystemTime;
+
+use http::{header, HeaderValue, Method, StatusCode};
+use lunatic::fs::{self, File};
+
+use crate::extract::path::FromParam;
+use crate::response::{IntoResponse, Response};
+use crate::{Handler, RequestContext};
+
+/// A [`Handler`] that serves files from a directory, resolving the file
+/// path from the request's URI.
+///
+/// # Example
+///
+/// ```
+/// use submillisecond::{router, serve_dir::ServeDir};
+///
+/// router! {
+///     GET "/assets" => ServeDir::new("./public").strip_prefix("/assets")
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ServeDir {
+    root: PathBuf,
+    strip_prefix: String,
+}
+
+impl ServeDir {
+    /// Creates a [`ServeDir`] rooted at `root`, serving files at the
+    /// request's full URI path.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        ServeDir {
+            root: root.into(),
+            strip_prefix: String::new(),
+        }
+    }
+
+    /// Strips `prefix` off the front of the request's URI path before
+    /// resolving it underneath `root`. Defaults to no prefix.
+    pub fn strip_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.strip_prefix = prefix.into();
+        self
+    }
+
+    fn resolve(&self, req: &RequestContext) -> Option<PathBuf> {
+        let path = req.uri().path();
+        let relative = path.strip_prefix(self.strip_prefix.as_str()).unwrap_or(path);
+        let relative = relative.trim_start_matches('/');
+        let relative = PathBuf::from_param(relative).ok()?;
+
+        // `FromParam`'s `PathBuf` impl already rejects `..` segments, but
+        // guard against an absolute segment (e.g. one smuggled through
+        // percent-decoding) escaping `root` too. No filesystem call is
+        // needed here: unlike `std::fs::canonicalize`, which doesn't work
+        // in the lunatic guest runtime, this is a plain path walk.
+        if relative.components().any(|component| {
+            !matches!(component, Component::Normal(_))
+        }) {
+            return None;
+        }
+
+        Some(self.root.join(relative))
+    }
+
+    fn serve(&self, req: &mut RequestContext) -> Response {
+        if req.method() != Method::GET && req.method() != Method::HEAD {
+            return (StatusCode::METHOD_NOT_ALLOWED, "Method Not Allowed").into_response();
+        }
+
+        let path = match self.resolve(req) {
+            Some(path) => path,
+            None => return (StatusCode::NOT_FOUND, "Not Found").into_response(),
+        };
+
+        // `fs::metadata` (the same `lunatic::fs` module the file read below
+        // uses) doubles as the existence and regular-file check, so path
+        // resolution never touches `std::fs`.
+        let metadata = match fs::metadata(&path) {
+            Ok(metadata) if metadata.is_file() => metadata,
+            _ => return (StatusCode::NOT_FOUND, "Not Found").into_response(),
+        };
+
+        let last_modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+        let etag = format!(
+            "\"{:x}-{:x}\"",
+            metadata.len(),
+            last_modified
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default()
+        );
+
+        if is_not_modified(req, &etag, last_modified) {
+            let mut response = (StatusCode::NOT_MODIFIED, "").into_response();
+            set_cache_headers(&mut response, &etag, last_modified);
+            return response;
+        }
+
+        if req.method() == Method::HEAD {
+            // A `HEAD` response describes the representation `GET` would
+            // return, but must not read the file or carry a body.
+            let content_type = mime_guess::from_path(&path).first_or_octet_stream();
+            let mut response = (StatusCode::OK, Vec::new()).into_response();
+            response.headers_mut().insert(
+                header::CONTENT_TYPE,
+                HeaderValue::from_str(content_type.as_ref()).unwrap(),
+            );
+            response.headers_mut().insert(
+                header::CONTENT_LENGTH,
+                HeaderValue::from(metadata.len()),
+            );
+            response
+                .headers_mut()
+                .insert(header::ACCEPT_RANGES, HeaderValue::from_static("bytes"));
+            set_cache_headers(&mut response, &etag, last_modified);
+            return response;
+        }
+
+        let mut file = match File::open(&path) {
+            Ok(file) => file,
+            Err(_) => return (StatusCode::NOT_FOUND, "Not Found").into_response(),
+        };
+        let mut contents = Vec::with_capacity(metadata.len() as usize);
+        if file.read_to_end(&mut contents).is_err() {
+            return (StatusCode::INTERNAL_SERVER_ERROR, "Internal Server Error").into_response();
+        }
+
+        let content_type = mime_guess::from_path(&path).first_or_octet_stream();
+
+        let mut response = match parse_range(req, contents.len()) {
+            Some(Some((start, end))) => {
+                let mut response = (
+                    StatusCode::PARTIAL_CONTENT,
+                    contents[start..=end].to_vec(),
+                )
+                    .into_response();
+                response.headers_mut().insert(
+                    header::CONTENT_RANGE,
+                    HeaderValue::from_str(&format!(
+                        "bytes {}-{}/{}",
+                        start,
+                        end,
+                        contents.len()
+                    ))
+                    .unwrap(),
+                );
+                response
+            }
+            Some(None) => {
+                return (
+                    StatusCode::RANGE_NOT_SATISFIABLE,
+                    "Range Not Satisfiable",
+                )
+                    .into_response()
+            }
+            None => (StatusCode::OK, contents).into_response(),
+        };
+
+        response.headers_mut().insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_str(content_type.as_ref()).unwrap(),
+        );
+        response
+            .headers_mut()
+            .insert(header::ACCEPT_RANGES, HeaderValue::from_static("bytes"));
+        set_cache_headers(&mut response, &etag, last_modified);
+        response
+    }
+}
+
+impl Handler for ServeDir {
+    fn handle(&self, mut req: RequestContext) -> Response {
+        self.serve(&mut req)
+    }
+}
+
+fn set_cache_headers(response: &mut Response, etag: &str, last_modified: SystemTime) {
+    if let Ok(etag) = HeaderValue::from_str(etag) {
+        response.headers_mut().insert(header::ETAG, etag);
+    }
+    response.headers_mut().insert(
+        header::LAST_MODIFIED,
+        HeaderValue::from_str(&httpdate::fmt_http_date(last_modified)).unwrap(),
+    );
+}
+
+fn is_not_modified(req: &RequestContext, etag: &str, last_modified: SystemTime) -> bool {
+    if let Some(if_none_match) = req
+        .headers()
+        .get(header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+    {
+        return if_none_match
+            .split(',')
+            .any(|candidate| candidate.trim() == etag || candidate.trim() == "*");
+    }
+
+    if let Some(if_modified_since) = req
+        .headers()
+        .get(header::IF_MODIFIED_SINCE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| httpdate::parse_http_date(value).ok())
+    {
+        return last_modified <= if_modified_since;
+    }
+
+    false
+}
+
+/// Parses a single-range `Range` header against a body of `len` bytes.
+///
+/// Returns `None` if there's no usable `Range` header (missing, malformed,
+/// not in bytes, or naming more than one range: serve the full body),
+/// `Some(None)` if the range is unsatisfiable, or `Some(Some((start, end)))`
+/// (inclusive) for a satisfiable single range.
+fn parse_range(req: &RequestContext, len: usize) -> Option<Option<(usize, usize)>> {
+    let header = req
+        .headers()
+        .get(header::RANGE)
+        .and_then(|value| value.to_str().ok())?;
+    let spec = header.strip_prefix("bytes=")?;
+    // Only a single range is supported; reject multi-range requests by
+    // falling back to serving the full body.
+    let mut ranges = spec.split(',');
+    let spec = ranges.next()?.trim();
+    if ranges.next().is_some() {
+        return None;
+    }
+    let (start, end) = spec.split_once('-')?;
+
+    let (start, end) = if start.is_empty() {
+        let suffix_len: usize = end.parse().ok()?;
+        if suffix_len >= len {
+            (0, len.saturating_sub(1))
+        } else {
+            (len - suffix_len, len.saturating_sub(1))
+        }
+    } else {
+        let start: usize = start.parse().ok()?;
+        let end = if end.is_empty() {
+            len.saturating_sub(1)
+        } else {
+            end.parse().ok()?
+        };
+        (start, end)
+    };
+
+    if start > end || end >= len {
+        return Some(None);
+    }
+
+    Some(Some((start, end)))
+}